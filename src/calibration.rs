@@ -0,0 +1,83 @@
+use crate::{cosine_similarity, EmbeddingValue, RerankerModel};
+
+/// Cross-model score normalization: remaps a raw relevance score (or cosine
+/// similarity) from a model-specific scale onto a comparable `[0, 1]` scale via a
+/// logistic shift, so a single cutoff threshold can be applied regardless of which
+/// model produced the score.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreCalibrator {
+    mean: f32,
+    std_dev: f32,
+}
+
+impl ScoreCalibrator {
+    /// Builds a calibrator from an explicit mean (`μ`) and standard deviation (`σ`)
+    /// of the score distribution being normalized.
+    pub fn new(mean: f32, std_dev: f32) -> Self {
+        Self { mean, std_dev }
+    }
+
+    /// A small built-in table of illustrative `(μ, σ)` placeholders, one per Jina
+    /// reranker model. These are **not** measured from real score distributions —
+    /// they only exist so the calibrator has somewhere to start. Callers who care
+    /// about accurate calibration should measure their own `(μ, σ)` from their
+    /// corpus and pass them to [`ScoreCalibrator::new`] instead.
+    pub fn for_model(model: &RerankerModel) -> Self {
+        let (mean, std_dev) = match model {
+            RerankerModel::RerankerV2BaseMultilingual => (0.5, 0.2),
+            RerankerModel::RerankerV1BaseEn => (0.45, 0.2),
+            RerankerModel::RerankerV1TinyEn => (0.4, 0.25),
+            RerankerModel::RerankerV1TurboEn => (0.4, 0.25),
+            RerankerModel::ColbertV1En => (0.5, 0.2),
+        };
+
+        Self::new(mean, std_dev)
+    }
+
+    /// Remaps `score` through a logistic shift, `1 / (1 + exp(-(score - μ) / σ))`,
+    /// producing a value in `[0, 1]` that preserves the original ordering. Works for
+    /// both rerank relevance scores and cosine similarities.
+    pub fn calibrate(&self, score: f32) -> f32 {
+        1.0 / (1.0 + (-(score - self.mean) / self.std_dev).exp())
+    }
+
+    /// Computes the cosine similarity between two embeddings (e.g. from an
+    /// [`crate::EmbeddingsResponse`]) via [`crate::cosine_similarity`] and remaps it
+    /// through this calibrator, exactly like [`ScoreCalibrator::calibrate`] does for
+    /// rerank relevance scores.
+    pub fn calibrate_embeddings_similarity(&self, a: &EmbeddingValue, b: &EmbeddingValue) -> f32 {
+        self.calibrate(cosine_similarity(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibrate_centers_mean_at_half() {
+        let calibrator = ScoreCalibrator::new(0.5, 0.2);
+
+        assert_eq!(calibrator.calibrate(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_calibrate_preserves_ordering() {
+        let calibrator = ScoreCalibrator::new(0.5, 0.2);
+
+        assert!(calibrator.calibrate(0.3) < calibrator.calibrate(0.6));
+        assert!(calibrator.calibrate(0.6) < calibrator.calibrate(0.9));
+    }
+
+    #[test]
+    fn test_calibrate_embeddings_similarity_matches_calibrate_of_cosine() {
+        let calibrator = ScoreCalibrator::new(0.5, 0.2);
+        let a = EmbeddingValue::Float(vec![1.0, 0.0]);
+        let b = EmbeddingValue::Float(vec![1.0, 0.0]);
+
+        assert_eq!(
+            calibrator.calibrate_embeddings_similarity(&a, &b),
+            calibrator.calibrate(1.0)
+        );
+    }
+}
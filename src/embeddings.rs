@@ -1,8 +1,10 @@
+use base64::Engine as _;
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{Jina, JinaError, Usage};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EmbeddingsModel {
     #[serde(rename = "jina-clip-v1")]
     ClipV1,
@@ -48,7 +50,7 @@ pub struct EmbeddingsRequest {
     pub normalized: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EmbeddingsInput {
     StringArray(Vec<String>),
@@ -57,31 +59,44 @@ pub enum EmbeddingsInput {
     Doc(Doc),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Doc {
     Text(TextDoc),
     Image(ImageDoc),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageDoc {
     pub image: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextDoc {
     pub text: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EmbeddingType {
     Single(EmbeddingTypeEnum),
     Multiple(Vec<EmbeddingTypeEnum>),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl EmbeddingType {
+    /// Whether this requests `ubinary` (alone or alongside other formats). The wire
+    /// format for `binary` and `ubinary` is identical (a plain JSON integer array),
+    /// so [`EmbeddingValue`]'s `Deserialize` impl can't always tell them apart on its
+    /// own; callers that asked for `ubinary` use this to disambiguate after the fact.
+    fn expects_ubinary(&self) -> bool {
+        match self {
+            EmbeddingType::Single(kind) => *kind == EmbeddingTypeEnum::Ubinary,
+            EmbeddingType::Multiple(kinds) => kinds.contains(&EmbeddingTypeEnum::Ubinary),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EmbeddingTypeEnum {
     Float,
@@ -100,25 +115,269 @@ pub struct EmbeddingsResponse {
 #[derive(Debug, Deserialize)]
 pub struct Embedding {
     pub index: usize,
-    pub embedding: Vec<f32>,
+    pub embedding: EmbeddingValue,
     pub object: String,
 }
 
+/// The decoded value of an [`Embedding`], covering every wire representation the
+/// Jina API can return depending on the request's `embedding_type`: a dense `f32`
+/// vector (`float`), a base64-packed `f32` vector (`base64`, already decoded here),
+/// or a quantized integer vector (`binary`/`ubinary`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum EmbeddingValue {
+    Float(Vec<f32>),
+    Int8(Vec<i8>),
+    UInt8(Vec<u8>),
+}
+
+impl EmbeddingValue {
+    /// Returns the embedding as a dense `f32` vector, dequantizing `int8`/`uint8`
+    /// payloads if necessary.
+    pub fn as_f32(&self) -> Vec<f32> {
+        match self {
+            EmbeddingValue::Float(values) => values.clone(),
+            EmbeddingValue::Int8(values) => values.iter().map(|&value| value as f32).collect(),
+            EmbeddingValue::UInt8(values) => values.iter().map(|&value| value as f32).collect(),
+        }
+    }
+
+    /// Corrects an `Int8` decode to `UInt8` without altering the contained values.
+    /// Used to disambiguate a `ubinary` response that happened to decode as `Int8`
+    /// because every value fit in `0..=127` (see [`EmbeddingType::expects_ubinary`]).
+    fn retag_as_ubinary(&mut self) {
+        if let EmbeddingValue::Int8(values) = self {
+            *self = EmbeddingValue::UInt8(values.iter().map(|&value| value as u8).collect());
+        }
+    }
+}
+
+/// Computes the cosine similarity between two embeddings, dequantizing `int8`/
+/// `uint8` payloads to `f32` first via [`EmbeddingValue::as_f32`]. Returns `0.0` if
+/// either embedding is a zero vector.
+///
+/// Pair this with [`crate::ScoreCalibrator::calibrate`] (or
+/// [`crate::ScoreCalibrator::calibrate_embeddings_similarity`]) to bring
+/// similarities from different embedding models onto the same `[0, 1]` scale as
+/// calibrated rerank scores.
+pub fn cosine_similarity(a: &EmbeddingValue, b: &EmbeddingValue) -> f32 {
+    let a = a.as_f32();
+    let b = b.as_f32();
+
+    let dot: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+impl<'de> Deserialize<'de> for EmbeddingValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct EmbeddingValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EmbeddingValueVisitor {
+            type Value = EmbeddingValue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a base64-encoded string or an array of numbers")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(value)
+                    .map_err(|err| {
+                        serde::de::Error::custom(format!("invalid base64 embedding: {err}"))
+                    })?;
+
+                let values = bytes
+                    .chunks_exact(4)
+                    .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                    .collect();
+
+                Ok(EmbeddingValue::Float(values))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut numbers = Vec::new();
+                while let Some(number) = seq.next_element::<serde_json::Number>()? {
+                    numbers.push(number);
+                }
+
+                // `binary` and `ubinary` are returned as plain JSON integers, one per
+                // dimension; try the narrower, signed representation first and fall
+                // back to unsigned, then to `float` for anything that doesn't fit.
+                if let Some(values) = numbers
+                    .iter()
+                    .map(|number| number.as_i64().and_then(|value| i8::try_from(value).ok()))
+                    .collect::<Option<Vec<i8>>>()
+                {
+                    return Ok(EmbeddingValue::Int8(values));
+                }
+
+                if let Some(values) = numbers
+                    .iter()
+                    .map(|number| number.as_u64().and_then(|value| u8::try_from(value).ok()))
+                    .collect::<Option<Vec<u8>>>()
+                {
+                    return Ok(EmbeddingValue::UInt8(values));
+                }
+
+                let values = numbers
+                    .iter()
+                    .map(|number| number.as_f64().unwrap_or_default() as f32)
+                    .collect();
+
+                Ok(EmbeddingValue::Float(values))
+            }
+        }
+
+        deserializer.deserialize_any(EmbeddingValueVisitor)
+    }
+}
+
 impl Jina {
     pub async fn embeddings(
         &self,
         request: EmbeddingsRequest,
     ) -> Result<EmbeddingsResponse, JinaError> {
-        self.post("/v1/embeddings", request).await
+        let expects_ubinary = request
+            .embedding_type
+            .as_ref()
+            .is_some_and(EmbeddingType::expects_ubinary);
+
+        let mut response: EmbeddingsResponse = self.post("/v1/embeddings", request).await?;
+
+        if expects_ubinary {
+            for embedding in &mut response.data {
+                embedding.embedding.retag_as_ubinary();
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Transparently chunks a `StringArray`/`DocArray` input into sub-batches of at
+    /// most `batch_size` items and dispatches them concurrently (at most
+    /// `max_concurrency` requests in flight at once), stitching the partial
+    /// responses back together in the original input order. A single `String`/`Doc`
+    /// input is sent as-is, equivalent to calling [`Jina::embeddings`].
+    ///
+    /// Use this instead of [`Jina::embeddings`] when embedding more inputs than the
+    /// Jina API accepts in a single call.
+    pub async fn embeddings_batched(
+        &self,
+        request: EmbeddingsRequest,
+        batch_size: usize,
+        max_concurrency: usize,
+    ) -> Result<EmbeddingsResponse, JinaError> {
+        let EmbeddingsRequest {
+            model,
+            input,
+            embedding_type,
+            normalized,
+        } = request;
+
+        let batch_size = batch_size.max(1);
+        let batches: Vec<EmbeddingsInput> = match input {
+            EmbeddingsInput::StringArray(items) => items
+                .chunks(batch_size)
+                .map(|chunk| EmbeddingsInput::StringArray(chunk.to_vec()))
+                .collect(),
+            EmbeddingsInput::DocArray(items) => items
+                .chunks(batch_size)
+                .map(|chunk| EmbeddingsInput::DocArray(chunk.to_vec()))
+                .collect(),
+            single => vec![single],
+        };
+
+        let mut offset = 0;
+        let jobs: Vec<(usize, EmbeddingsInput)> = batches
+            .into_iter()
+            .map(|batch| {
+                let job_offset = offset;
+                offset += batch_len(&batch);
+                (job_offset, batch)
+            })
+            .collect();
+
+        let results: Vec<Result<(usize, EmbeddingsResponse), JinaError>> = stream::iter(jobs)
+            .map(|(job_offset, batch)| {
+                let request = EmbeddingsRequest {
+                    model: model.clone(),
+                    input: batch,
+                    embedding_type: embedding_type.clone(),
+                    normalized,
+                };
+                async move {
+                    self.embeddings(request)
+                        .await
+                        .map(|response| (job_offset, response))
+                }
+            })
+            .buffer_unordered(max_concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut model = None;
+        let mut usage = Usage {
+            prompt_tokens: 0,
+            total_tokens: 0,
+        };
+        let mut data = Vec::new();
+
+        for result in results {
+            let (job_offset, response) = result?;
+            model.get_or_insert(response.model);
+            usage.prompt_tokens += response.usage.prompt_tokens;
+            usage.total_tokens += response.usage.total_tokens;
+            data.extend(response.data.into_iter().map(|mut embedding| {
+                embedding.index += job_offset;
+                embedding
+            }));
+        }
+
+        data.sort_by_key(|embedding| embedding.index);
+
+        Ok(EmbeddingsResponse {
+            model: model.unwrap_or_default(),
+            data,
+            usage,
+        })
+    }
+}
+
+fn batch_len(input: &EmbeddingsInput) -> usize {
+    match input {
+        EmbeddingsInput::StringArray(items) => items.len(),
+        EmbeddingsInput::DocArray(items) => items.len(),
+        EmbeddingsInput::String(_) | EmbeddingsInput::Doc(_) => 1,
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use http_client_reqwest::HttpClientReqwest;
+
     use super::*;
 
     #[tokio::test]
     async fn test_embeddings() {
+        let http_client = Arc::new(HttpClientReqwest::default());
         let mut server = mockito::Server::new();
         let mock = server
             .mock("POST", "/v1/embeddings")
@@ -145,8 +404,9 @@ mod tests {
             .create();
 
         let client = Jina::builder()
-            .api_key("test-key".to_string())
-            .base_url(server.url())
+            .with_http_client(http_client)
+            .with_api_key("test-key".to_string())
+            .with_base_url(server.url())
             .build()
             .unwrap();
 
@@ -162,9 +422,209 @@ mod tests {
         assert_eq!(response.model, "test-model");
         assert_eq!(response.data.len(), 1);
         assert_eq!(response.data[0].index, 0);
-        assert_eq!(response.data[0].embedding, vec![0.1, 0.2, 0.3]);
+        assert_eq!(response.data[0].embedding.as_f32(), vec![0.1, 0.2, 0.3]);
         assert_eq!(response.usage.total_tokens, 3);
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_embeddings_disambiguates_ubinary_from_requested_type() {
+        let http_client = Arc::new(HttpClientReqwest::default());
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/embeddings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"
+                {
+                    "model": "test-model",
+                    "data": [
+                        {
+                            "index": 0,
+                            "embedding": [0, 50, 100],
+                            "object": "embedding"
+                        }
+                    ],
+                    "usage": {
+                        "total_tokens": 3,
+                        "prompt_tokens": 3
+                    }
+                }
+            "#,
+            )
+            .create();
+
+        let client = Jina::builder()
+            .with_http_client(http_client)
+            .with_api_key("test-key".to_string())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let request = EmbeddingsRequest {
+            model: EmbeddingsModel::ClipV1,
+            input: EmbeddingsInput::String("Hello, world!".to_string()),
+            embedding_type: Some(EmbeddingType::Single(EmbeddingTypeEnum::Ubinary)),
+            normalized: None,
+        };
+
+        let response = client.embeddings(request).await.unwrap();
+
+        assert_eq!(
+            response.data[0].embedding,
+            EmbeddingValue::UInt8(vec![0, 50, 100])
+        );
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_embeddings_batched_reassembles_batches_in_original_order() {
+        let http_client = Arc::new(HttpClientReqwest::default());
+        let mut server = mockito::Server::new();
+
+        let mock_first_batch = server
+            .mock("POST", "/v1/embeddings")
+            .match_body(mockito::Matcher::Regex("\"first\"".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"
+                {
+                    "model": "test-model",
+                    "data": [
+                        {"index": 0, "embedding": [1.0, 0.0], "object": "embedding"},
+                        {"index": 1, "embedding": [2.0, 0.0], "object": "embedding"}
+                    ],
+                    "usage": {
+                        "total_tokens": 2,
+                        "prompt_tokens": 2
+                    }
+                }
+            "#,
+            )
+            .create();
+
+        let mock_second_batch = server
+            .mock("POST", "/v1/embeddings")
+            .match_body(mockito::Matcher::Regex("\"third\"".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"
+                {
+                    "model": "test-model",
+                    "data": [
+                        {"index": 0, "embedding": [3.0, 0.0], "object": "embedding"},
+                        {"index": 1, "embedding": [4.0, 0.0], "object": "embedding"}
+                    ],
+                    "usage": {
+                        "total_tokens": 2,
+                        "prompt_tokens": 2
+                    }
+                }
+            "#,
+            )
+            .create();
+
+        let client = Jina::builder()
+            .with_http_client(http_client)
+            .with_api_key("test-key".to_string())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let request = EmbeddingsRequest {
+            model: EmbeddingsModel::ClipV1,
+            input: EmbeddingsInput::StringArray(vec![
+                "first".to_string(),
+                "second".to_string(),
+                "third".to_string(),
+                "fourth".to_string(),
+            ]),
+            embedding_type: None,
+            normalized: None,
+        };
+
+        // batch_size 2 splits the 4 inputs into 2 sub-batches, dispatched
+        // concurrently (max_concurrency 2); the server assigns indices 0/1
+        // within each batch, independent of completion order.
+        let response = client.embeddings_batched(request, 2, 2).await.unwrap();
+
+        let indices: Vec<usize> = response
+            .data
+            .iter()
+            .map(|embedding| embedding.index)
+            .collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+
+        let values: Vec<Vec<f32>> = response
+            .data
+            .iter()
+            .map(|embedding| embedding.embedding.as_f32())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                vec![1.0, 0.0],
+                vec![2.0, 0.0],
+                vec![3.0, 0.0],
+                vec![4.0, 0.0],
+            ]
+        );
+
+        assert_eq!(response.usage.prompt_tokens, 4);
+        assert_eq!(response.usage.total_tokens, 4);
+
+        mock_first_batch.assert();
+        mock_second_batch.assert();
+    }
+
+    #[test]
+    fn test_embedding_value_float() {
+        let value: EmbeddingValue = serde_json::from_str("[0.1, 0.2, 0.3]").unwrap();
+
+        assert_eq!(value, EmbeddingValue::Float(vec![0.1, 0.2, 0.3]));
+    }
+
+    #[test]
+    fn test_embedding_value_base64() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(
+            [1.0f32, -2.5, 3.25]
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect::<Vec<u8>>(),
+        );
+
+        let value: EmbeddingValue =
+            serde_json::from_str(&format!("\"{encoded}\"")).unwrap();
+
+        assert_eq!(value.as_f32(), vec![1.0, -2.5, 3.25]);
+    }
+
+    #[test]
+    fn test_embedding_value_binary() {
+        let value: EmbeddingValue = serde_json::from_str("[-128, 0, 127]").unwrap();
+
+        assert_eq!(value, EmbeddingValue::Int8(vec![-128, 0, 127]));
+    }
+
+    #[test]
+    fn test_embedding_value_ubinary() {
+        let value: EmbeddingValue = serde_json::from_str("[0, 200, 255]").unwrap();
+
+        assert_eq!(value, EmbeddingValue::UInt8(vec![0, 200, 255]));
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        let a = EmbeddingValue::Float(vec![1.0, 0.0]);
+        let b = EmbeddingValue::Float(vec![0.0, 1.0]);
+        let c = EmbeddingValue::Float(vec![2.0, 0.0]);
+
+        assert_eq!(cosine_similarity(&a, &b), 0.0);
+        assert_eq!(cosine_similarity(&a, &c), 1.0);
+    }
 }
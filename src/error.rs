@@ -0,0 +1,105 @@
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::RetryStrategy;
+
+#[derive(Debug, Error)]
+pub enum JinaError {
+    #[error("jina api returned an error: {0:?}")]
+    HttpError(HttpError),
+
+    /// A connection-level failure (timeout, DNS, TLS, broken pipe, ...). Transient by
+    /// nature, so the retry loop treats it the same as a 5xx status.
+    #[error("transport error: {0}")]
+    Transport(anyhow::Error),
+
+    /// Any other failure (request serialization, response decoding, ...). Retrying
+    /// these wouldn't change the outcome, since the input that produced them doesn't
+    /// change between attempts.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl JinaError {
+    /// Classifies this error for the retry loop in [`crate::Jina::post`] /
+    /// [`crate::Jina::reader`]. Connection/timeout errors are always worth retrying;
+    /// HTTP errors are classified by status code; anything else is deterministic and
+    /// won't succeed on a second attempt.
+    pub(crate) fn retry_strategy(&self) -> RetryStrategy {
+        match self {
+            JinaError::HttpError(http_error) => RetryStrategy::from_status(http_error.status),
+            JinaError::Transport(_) => RetryStrategy::Retry,
+            JinaError::Other(_) => RetryStrategy::GiveUp,
+        }
+    }
+
+    /// The server-provided `Retry-After` value (in seconds), if any.
+    pub(crate) fn retry_after(&self) -> Option<u64> {
+        match self {
+            JinaError::HttpError(http_error) => http_error.retry_after,
+            JinaError::Transport(_) | JinaError::Other(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HttpError {
+    pub status: u16,
+    pub payload: Option<HttpErrorPayload>,
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HttpErrorPayload {
+    pub detail: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_strategy_http_error_classifies_by_status() {
+        let err = JinaError::HttpError(HttpError {
+            status: 429,
+            payload: None,
+            retry_after: None,
+        });
+
+        assert_eq!(err.retry_strategy(), RetryStrategy::RetryAfterRateLimit);
+    }
+
+    #[test]
+    fn test_retry_strategy_transport_is_always_retry() {
+        let err = JinaError::Transport(anyhow::anyhow!("connection reset"));
+
+        assert_eq!(err.retry_strategy(), RetryStrategy::Retry);
+    }
+
+    #[test]
+    fn test_retry_strategy_other_is_give_up() {
+        let err = JinaError::Other(anyhow::anyhow!("invalid request body"));
+
+        assert_eq!(err.retry_strategy(), RetryStrategy::GiveUp);
+    }
+
+    #[test]
+    fn test_retry_after_read_from_http_error() {
+        let err = JinaError::HttpError(HttpError {
+            status: 429,
+            payload: None,
+            retry_after: Some(30),
+        });
+
+        assert_eq!(err.retry_after(), Some(30));
+    }
+
+    #[test]
+    fn test_retry_after_none_for_non_http_errors() {
+        let transport = JinaError::Transport(anyhow::anyhow!("timed out"));
+        let other = JinaError::Other(anyhow::anyhow!("bad input"));
+
+        assert_eq!(transport.retry_after(), None);
+        assert_eq!(other.retry_after(), None);
+    }
+}
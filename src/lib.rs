@@ -1,14 +1,16 @@
+mod calibration;
 mod embeddings;
 mod error;
 mod reader;
 mod rerank;
+mod retry;
 
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
 use http_client::{
     http::{
-        header::{AUTHORIZATION, CONTENT_TYPE},
+        header::{ACCEPT_ENCODING, AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE, RETRY_AFTER},
         method::Method,
         HeaderMap, HeaderValue,
     },
@@ -17,10 +19,13 @@ use http_client::{
 use secrecy::{ExposeSecret, SecretString};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-pub use crate::{embeddings::*, error::*, reader::*, rerank::*};
+pub use crate::{calibration::*, embeddings::*, error::*, reader::*, rerank::*, retry::*};
 
 pub const BASE_URL: &str = "https://api.jina.ai";
 
+/// Default number of retry attempts when [`JinaBuilder::with_max_retries`] is not set.
+pub const DEFAULT_MAX_RETRIES: u32 = 0;
+
 /// Represents usage information for the request
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Usage {
@@ -34,12 +39,14 @@ pub struct Jina {
     http_client: Arc<dyn HttpClient>,
     api_key: SecretString,
     pub(crate) base_url: String,
+    pub(crate) max_retries: u32,
 }
 
 pub struct JinaBuilder {
     http_client: Option<Arc<dyn HttpClient>>,
     api_key: Option<SecretString>,
     base_url: Option<String>,
+    max_retries: Option<u32>,
 }
 
 impl Jina {
@@ -48,6 +55,7 @@ impl Jina {
             http_client: None,
             api_key: None,
             base_url: None,
+            max_retries: None,
         }
     }
 
@@ -57,18 +65,50 @@ impl Jina {
         S: Serialize,
         D: DeserializeOwned,
     {
+        let path = path.into();
         let headers = self.default_headers();
 
+        let mut attempt = 0;
+        loop {
+            let result = self.send_once(&path, &headers, &request).await;
+
+            let err = match result {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+
+            let strategy = err.retry_strategy();
+            if attempt >= self.max_retries || strategy == RetryStrategy::GiveUp {
+                return Err(err);
+            }
+
+            attempt += 1;
+            tokio::time::sleep(strategy.backoff(attempt, err.retry_after())).await;
+        }
+    }
+
+    async fn send_once<S, D>(
+        &self,
+        path: &str,
+        headers: &HeaderMap,
+        request: &S,
+    ) -> Result<D, JinaError>
+    where
+        S: Serialize,
+        D: DeserializeOwned,
+    {
+        let built_request = Request::builder()
+            .uri(format!("{}{}", self.base_url, path))
+            .method(Method::POST)
+            .headers(headers.clone())
+            .json(request)
+            .map_err(JinaError::Other)?;
+
         let response = self
             .http_client
-            .send(
-                Request::builder()
-                    .uri(format!("{}{}", self.base_url, path.into()))
-                    .method(Method::POST)
-                    .headers(headers)
-                    .json(&request)?,
-            )
-            .await?;
+            .send(built_request)
+            .await
+            .map_err(JinaError::Transport)?;
 
         Self::handle_response(response).await
     }
@@ -86,6 +126,26 @@ impl Jina {
                 .parse()
                 .expect("couldn't create header value"),
         );
+
+        let codecs: Vec<&str> = vec![
+            #[cfg(feature = "gzip")]
+            "gzip",
+            #[cfg(feature = "brotli")]
+            "br",
+            #[cfg(feature = "zstd")]
+            "zstd",
+        ];
+
+        if !codecs.is_empty() {
+            headers.insert(
+                ACCEPT_ENCODING,
+                codecs
+                    .join(", ")
+                    .parse()
+                    .expect("couldn't create header value"),
+            );
+        }
+
         headers
     }
 
@@ -94,17 +154,70 @@ impl Jina {
         D: DeserializeOwned,
     {
         let status = response.status();
+        let content_encoding = response
+            .headers()
+            .get(CONTENT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
         if !status.is_success() {
-            let payload = response.json::<HttpErrorPayload>().await.ok();
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok());
+            let payload = response
+                .bytes()
+                .await
+                .ok()
+                .and_then(|bytes| decode_body(&bytes, content_encoding.as_deref()).ok())
+                .and_then(|bytes| serde_json::from_slice::<HttpErrorPayload>(&bytes).ok());
             return Err(JinaError::HttpError(HttpError {
                 status: status.as_u16(),
                 payload,
+                retry_after,
             }));
         }
 
-        let response = response.text().await?;
+        let bytes = response.bytes().await.map_err(JinaError::Transport)?;
+        let bytes = decode_body(&bytes, content_encoding.as_deref())?;
 
-        Ok(serde_json::from_str(&response).unwrap())
+        serde_json::from_slice(&bytes).map_err(|err| JinaError::Other(anyhow!(err)))
+    }
+}
+
+/// Decompresses a response body according to its `Content-Encoding` header, falling
+/// back to the body as-is for identity encoding or for a codec whose feature isn't
+/// enabled.
+#[cfg_attr(
+    not(any(feature = "gzip", feature = "brotli", feature = "zstd")),
+    allow(unused_variables)
+)]
+fn decode_body(bytes: &[u8], content_encoding: Option<&str>) -> Result<Vec<u8>, JinaError> {
+    match content_encoding {
+        #[cfg(feature = "gzip")]
+        Some("gzip") => {
+            use std::io::Read;
+
+            let mut decoded = Vec::new();
+            flate2::read::GzDecoder::new(bytes)
+                .read_to_end(&mut decoded)
+                .map_err(|err| anyhow!(err))?;
+            Ok(decoded)
+        }
+        #[cfg(feature = "brotli")]
+        Some("br") => {
+            use std::io::Read;
+
+            let mut decoded = Vec::new();
+            brotli::Decompressor::new(bytes, 4096)
+                .read_to_end(&mut decoded)
+                .map_err(|err| anyhow!(err))?;
+            Ok(decoded)
+        }
+        #[cfg(feature = "zstd")]
+        Some("zstd") => Ok(zstd::stream::decode_all(bytes).map_err(|err| anyhow!(err))?),
+        _ => Ok(bytes.to_vec()),
     }
 }
 
@@ -130,12 +243,228 @@ impl JinaBuilder {
         self
     }
 
+    /// Sets the maximum number of retry attempts for transient failures (connection
+    /// errors, 5xx statuses, and 429s). Defaults to [`DEFAULT_MAX_RETRIES`] (no
+    /// retries) when unset.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
     pub fn build(self) -> Result<Jina> {
         Ok(Jina {
             http_client: self.http_client.ok_or_else(|| anyhow!("you must provide an HttpClient implementation"))?,
             api_key: self.api_key.or_else(|| std::env::var("EXA_API_KEY").ok().map(SecretString::new))
                 .ok_or_else(|| anyhow!("API key is required. Set it explicitly or use the EXA_API_KEY environment variable"))?,
             base_url: self.base_url.unwrap_or_else(|| BASE_URL.to_string()),
+            max_retries: self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use http_client_reqwest::HttpClientReqwest;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn test_client(base_url: String, max_retries: u32) -> Jina {
+        Jina::builder()
+            .with_http_client(Arc::new(HttpClientReqwest::default()))
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_max_retries(max_retries)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_retries_on_5xx_until_max_retries_then_gives_up() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/test")
+            .with_status(503)
+            .expect(3)
+            .create();
+
+        let client = test_client(server.url(), 2);
+
+        let result: Result<Value, JinaError> = client.post("/v1/test", json!({})).await;
+
+        assert!(matches!(
+            result,
+            Err(JinaError::HttpError(HttpError { status: 503, .. }))
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_post_retries_on_429_until_max_retries_then_gives_up() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/test")
+            .with_status(429)
+            .expect(2)
+            .create();
+
+        let client = test_client(server.url(), 1);
+
+        let result: Result<Value, JinaError> = client.post("/v1/test", json!({})).await;
+
+        assert!(matches!(
+            result,
+            Err(JinaError::HttpError(HttpError { status: 429, .. }))
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_post_gives_up_immediately_on_non_retryable_status() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/test")
+            .with_status(404)
+            .expect(1)
+            .create();
+
+        let client = test_client(server.url(), 5);
+
+        let result: Result<Value, JinaError> = client.post("/v1/test", json!({})).await;
+
+        assert!(matches!(
+            result,
+            Err(JinaError::HttpError(HttpError { status: 404, .. }))
+        ));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_reader_retries_on_5xx_until_max_retries_then_gives_up() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/").with_status(503).expect(2).create();
+
+        let client = test_client(server.url(), 1);
+
+        let result = client
+            .reader(ReaderRequest {
+                url: "https://example.com".to_string(),
+                return_format: None,
+                no_cache: None,
+                wait_for_selector: None,
+                target_selector: None,
+                timeout: None,
+                proxy_url: None,
+                locale: None,
+            })
+            .await;
+
+        assert!(result.is_err());
+        mock.assert();
+    }
+
+    const EMBEDDINGS_BODY: &[u8] = br#"{
+        "model": "test-model",
+        "data": [
+            {
+                "index": 0,
+                "embedding": [0.1, 0.2, 0.3],
+                "object": "embedding"
+            }
+        ],
+        "usage": {
+            "total_tokens": 3,
+            "prompt_tokens": 3
+        }
+    }"#;
+
+    async fn assert_embeddings_roundtrip_through_encoding(encoding: &str, body: Vec<u8>) {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/embeddings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("content-encoding", encoding)
+            .with_body(body)
+            .create();
+
+        let client = test_client(server.url(), 0);
+
+        let response = client
+            .embeddings(EmbeddingsRequest {
+                model: EmbeddingsModel::ClipV1,
+                input: EmbeddingsInput::String("Hello, world!".to_string()),
+                embedding_type: None,
+                normalized: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.model, "test-model");
+        assert_eq!(response.data[0].embedding.as_f32(), vec![0.1, 0.2, 0.3]);
+        mock.assert();
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_handle_response_decompresses_gzip_body() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(EMBEDDINGS_BODY).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_embeddings_roundtrip_through_encoding("gzip", compressed).await;
+    }
+
+    #[cfg(feature = "brotli")]
+    #[tokio::test]
+    async fn test_handle_response_decompresses_brotli_body() {
+        use std::io::Write;
+
+        let mut compressed = Vec::new();
+        {
+            let mut encoder =
+                brotli::CompressorWriter::new(&mut compressed, 4096, 5, 22);
+            encoder.write_all(EMBEDDINGS_BODY).unwrap();
+        }
+
+        assert_embeddings_roundtrip_through_encoding("br", compressed).await;
+    }
+
+    #[cfg(feature = "zstd")]
+    #[tokio::test]
+    async fn test_handle_response_decompresses_zstd_body() {
+        let compressed = zstd::stream::encode_all(EMBEDDINGS_BODY, 0).unwrap();
+
+        assert_embeddings_roundtrip_through_encoding("zstd", compressed).await;
+    }
+
+    #[tokio::test]
+    async fn test_handle_response_returns_error_instead_of_panicking_on_undecodable_body() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/embeddings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("content-encoding", "deflate")
+            .with_body(b"not actually json".to_vec())
+            .create();
+
+        let client = test_client(server.url(), 0);
+
+        let result = client
+            .embeddings(EmbeddingsRequest {
+                model: EmbeddingsModel::ClipV1,
+                input: EmbeddingsInput::String("Hello, world!".to_string()),
+                embedding_type: None,
+                normalized: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(JinaError::Other(_))));
+        mock.assert();
+    }
+}
@@ -1,12 +1,12 @@
 use anyhow::{anyhow, Result};
 use http_client::{
-    http::{header::ACCEPT, HeaderValue, Method},
+    http::{header::ACCEPT, HeaderMap, HeaderValue, Method},
     Request, RequestBuilderExt,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::Jina;
+use crate::{Jina, JinaError, RetryStrategy};
 
 #[derive(Serialize, Deserialize)]
 pub struct ReaderUsage {
@@ -114,19 +114,43 @@ impl Jina {
             headers.insert("X-Wait-For-Selector", wait_for_selector.parse()?);
         }
 
+        let mut attempt = 0;
+        loop {
+            let err = match self.send_reader_once(&headers, &request.url).await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+
+            let strategy = err.retry_strategy();
+            if attempt >= self.max_retries || strategy == RetryStrategy::GiveUp {
+                return Err(err.into());
+            }
+
+            attempt += 1;
+            tokio::time::sleep(strategy.backoff(attempt, err.retry_after())).await;
+        }
+    }
+
+    async fn send_reader_once(
+        &self,
+        headers: &HeaderMap,
+        url: &str,
+    ) -> Result<ReaderResponse, JinaError> {
+        let built_request = Request::builder()
+            .uri(self.base_url.clone())
+            .method(Method::POST)
+            .headers(headers.clone())
+            .json(json! ({
+                "url": url
+            }))
+            .map_err(JinaError::Other)?;
+
         let response = self
             .http_client
-            .send(
-                Request::builder()
-                    .uri(self.base_url.clone())
-                    .method(Method::POST)
-                    .headers(headers)
-                    .json(json! ({
-                        "url": request.url
-                    }))?,
-            )
-            .await?;
-
-        Ok(Self::handle_response(response).await?)
+            .send(built_request)
+            .await
+            .map_err(JinaError::Transport)?;
+
+        Self::handle_response(response).await
     }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::embeddings::TextDoc;
-use crate::{Jina, JinaError, Usage};
+use crate::{Jina, JinaError, ScoreCalibrator, Usage};
 
 /// The identifier of the model.
 ///
@@ -68,6 +68,10 @@ pub struct RankedResult {
     pub index: usize,
     pub document: RankedDocument,
     pub relevance_score: f32,
+    /// The `relevance_score` remapped onto a comparable `[0, 1]` scale by
+    /// [`Jina::rerank_calibrated`]. `None` when no [`ScoreCalibrator`] was supplied.
+    #[serde(default, skip_deserializing)]
+    pub calibrated_score: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,6 +83,23 @@ impl Jina {
     pub async fn rerank(&self, request: RerankRequest) -> Result<RerankResponse, JinaError> {
         self.post("/v1/rerank", request).await
     }
+
+    /// Calls [`Jina::rerank`] and remaps each result's `relevance_score` through
+    /// `calibrator`, storing the normalized `[0, 1]` score in
+    /// [`RankedResult::calibrated_score`] alongside the original.
+    pub async fn rerank_calibrated(
+        &self,
+        request: RerankRequest,
+        calibrator: &ScoreCalibrator,
+    ) -> Result<RerankResponse, JinaError> {
+        let mut response = self.rerank(request).await?;
+
+        for result in &mut response.results {
+            result.calibrated_score = Some(calibrator.calibrate(result.relevance_score));
+        }
+
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -145,4 +166,64 @@ mod tests {
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_rerank_calibrated() {
+        let http_client = Arc::new(HttpClientReqwest::default());
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/rerank")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"
+                {
+                    "model": "test-model",
+                    "results": [
+                        {
+                            "index": 0,
+                            "document": {
+                                "text": "Relevant document"
+                            },
+                            "relevance_score": 0.9
+                        }
+                    ],
+                    "usage": {
+                        "total_tokens": 5,
+                        "prompt_tokens": 5
+                    }
+                }
+            "#,
+            )
+            .create();
+
+        let client = Jina::builder()
+            .with_http_client(http_client)
+            .with_api_key("test-key".to_string())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let request = RerankRequest {
+            model: RerankerModel::ColbertV1En,
+            query: QueryType::String("Test query".to_string()),
+            documents: DocumentType::Strings(vec!["Relevant document".to_string()]),
+            top_n: None,
+            return_documents: None,
+        };
+
+        let calibrator = ScoreCalibrator::new(0.5, 0.2);
+        let response = client
+            .rerank_calibrated(request, &calibrator)
+            .await
+            .unwrap();
+
+        assert_eq!(response.results[0].relevance_score, 0.9);
+        assert_eq!(
+            response.results[0].calibrated_score,
+            Some(calibrator.calibrate(0.9))
+        );
+
+        mock.assert();
+    }
 }
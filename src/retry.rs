@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+/// How a failed attempt in [`crate::Jina::post`] / [`crate::Jina::reader`] should be
+/// handled by the retry loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// Not a transient failure: stop retrying and surface the error to the caller.
+    GiveUp,
+    /// A transient failure (connection/timeout error, or a 5xx status): retry after a
+    /// short backoff.
+    Retry,
+    /// The server asked us to slow down (429, optionally with a `Retry-After`
+    /// header): retry after a longer backoff.
+    RetryAfterRateLimit,
+}
+
+impl RetryStrategy {
+    /// Classifies a response by its HTTP status code.
+    pub(crate) fn from_status(status: u16) -> Self {
+        match status {
+            429 => RetryStrategy::RetryAfterRateLimit,
+            500..=599 => RetryStrategy::Retry,
+            _ => RetryStrategy::GiveUp,
+        }
+    }
+
+    /// Computes how long to sleep before attempt `n`, preferring the server's
+    /// `Retry-After` value (in seconds) when one was provided.
+    pub(crate) fn backoff(&self, attempt: u32, retry_after: Option<u64>) -> Duration {
+        match self {
+            RetryStrategy::Retry => Duration::from_millis(pow10_millis(attempt)),
+            RetryStrategy::RetryAfterRateLimit => match retry_after {
+                Some(seconds) => Duration::from_secs(seconds),
+                None => Duration::from_millis(100u64.saturating_add(pow10_millis(attempt))),
+            },
+            RetryStrategy::GiveUp => Duration::ZERO,
+        }
+    }
+}
+
+/// `10^attempt`, saturating instead of overflowing/panicking once `attempt` is large
+/// enough to blow past `u64` (happens around `attempt == 20`, which is already far
+/// past any sane `max_retries`).
+fn pow10_millis(attempt: u32) -> u64 {
+    10u64.checked_pow(attempt).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_status_classifies_429_as_rate_limit() {
+        assert_eq!(
+            RetryStrategy::from_status(429),
+            RetryStrategy::RetryAfterRateLimit
+        );
+    }
+
+    #[test]
+    fn test_from_status_classifies_5xx_as_retry() {
+        assert_eq!(RetryStrategy::from_status(500), RetryStrategy::Retry);
+        assert_eq!(RetryStrategy::from_status(503), RetryStrategy::Retry);
+        assert_eq!(RetryStrategy::from_status(599), RetryStrategy::Retry);
+    }
+
+    #[test]
+    fn test_from_status_classifies_other_statuses_as_give_up() {
+        assert_eq!(RetryStrategy::from_status(400), RetryStrategy::GiveUp);
+        assert_eq!(RetryStrategy::from_status(404), RetryStrategy::GiveUp);
+        assert_eq!(RetryStrategy::from_status(200), RetryStrategy::GiveUp);
+    }
+
+    #[test]
+    fn test_backoff_retry_after_takes_precedence_over_exponent() {
+        let backoff =
+            RetryStrategy::RetryAfterRateLimit.backoff(1, Some(5));
+
+        assert_eq!(backoff, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_backoff_rate_limit_without_retry_after_falls_back_to_exponent() {
+        let backoff = RetryStrategy::RetryAfterRateLimit.backoff(2, None);
+
+        assert_eq!(backoff, Duration::from_millis(100 + pow10_millis(2)));
+    }
+
+    #[test]
+    fn test_backoff_retry_ignores_retry_after() {
+        let backoff = RetryStrategy::Retry.backoff(3, Some(5));
+
+        assert_eq!(backoff, Duration::from_millis(pow10_millis(3)));
+    }
+
+    #[test]
+    fn test_backoff_give_up_is_zero() {
+        assert_eq!(RetryStrategy::GiveUp.backoff(1, Some(5)), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_pow10_millis_saturates_instead_of_overflowing() {
+        assert_eq!(pow10_millis(20), u64::MAX);
+        assert_eq!(pow10_millis(3), 1_000);
+    }
+}